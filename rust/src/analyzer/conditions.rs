@@ -0,0 +1,142 @@
+//! Declarative condition evaluation for rules, modeled on Chromium's GPU
+//! control list: a rule's `conditions` name a field, an operator, and one or
+//! two comparison values, and are evaluated generically against collected
+//! facts instead of being hand-coded per rule id.
+
+use std::cmp::Ordering;
+
+use crate::types::{Condition, ConditionOp, DriverInfo, GPUInfo, SystemInfo};
+
+/// Facts available to a condition, scoped to at most one GPU at a time. Rules
+/// with a `gpu.*` field are evaluated once per GPU; rules without one are
+/// evaluated once against just the system/driver/counts.
+pub struct FactContext<'a> {
+    pub system: &'a SystemInfo,
+    pub driver: &'a DriverInfo,
+    pub gpu: Option<&'a GPUInfo>,
+    pub gpu_count: usize,
+    pub nvidia_gpu_count: usize,
+}
+
+/// Fields whose values are version strings and must be compared
+/// numerically, segment by segment, rather than lexically.
+const VERSION_FIELDS: &[&str] = &["driver_version", "cuda_version", "gpu.driver_version"];
+
+/// Resolves a dotted field name (e.g. `gpu.temperature_c`) against the
+/// context. Returns `None` when the field references a GPU but no GPU is in
+/// scope, or when the field name is unknown.
+pub fn resolve_field(field: &str, ctx: &FactContext) -> Option<String> {
+    match field {
+        "driver_version" => Some(ctx.driver.version.clone()),
+        "cuda_version" => Some(ctx.driver.cuda_version.clone()),
+        "os_name" => Some(ctx.system.os_name.clone()),
+        "os_version" => Some(ctx.system.os_version.clone()),
+        "ram_total_mb" => Some(ctx.system.ram_total_mb.to_string()),
+        "gpu_count" => Some(ctx.gpu_count.to_string()),
+        "nvidia_gpu_count" => Some(ctx.nvidia_gpu_count.to_string()),
+        "non_nvidia_gpu_count" => Some((ctx.gpu_count - ctx.nvidia_gpu_count).to_string()),
+        "gpu.name" => ctx.gpu.map(|g| g.name.clone()),
+        "gpu.driver_version" => ctx.gpu.map(|g| g.driver_version.clone()),
+        "gpu.vram_total_mb" => ctx.gpu.map(|g| g.vram_total_mb.to_string()),
+        "gpu.temperature_c" => ctx.gpu.map(|g| g.temperature_c.to_string()),
+        "gpu.gpu_utilization_pct" => ctx.gpu.map(|g| g.gpu_utilization_pct.to_string()),
+        "gpu.power_usage_w" => ctx.gpu.map(|g| g.power_usage_w.to_string()),
+        _ => None,
+    }
+}
+
+/// Whether every condition in `conditions` matches the given context (AND
+/// semantics, matching a single Chromium control-list entry).
+pub fn conditions_match(conditions: &[Condition], ctx: &FactContext) -> bool {
+    conditions.iter().all(|c| condition_matches(c, ctx))
+}
+
+fn condition_matches(cond: &Condition, ctx: &FactContext) -> bool {
+    if cond.op == ConditionOp::Any {
+        return true;
+    }
+
+    let actual = match resolve_field(&cond.field, ctx) {
+        Some(v) => v,
+        None => return false,
+    };
+
+    match cond.op {
+        ConditionOp::Any => true,
+        ConditionOp::Eq => cond.value.as_deref().is_some_and(|v| values_eq(&cond.field, &actual, v)),
+        ConditionOp::Lt => compare(&cond.field, &actual, cond.value.as_deref().unwrap_or(""))
+            .is_some_and(|o| o == Ordering::Less),
+        ConditionOp::Gt => compare(&cond.field, &actual, cond.value.as_deref().unwrap_or(""))
+            .is_some_and(|o| o == Ordering::Greater),
+        ConditionOp::Between => {
+            let lo = cond.value.as_deref().unwrap_or("");
+            let hi = cond.value2.as_deref().unwrap_or("");
+            let above_lo = compare(&cond.field, &actual, lo).is_some_and(|o| o != Ordering::Less);
+            let below_hi = compare(&cond.field, &actual, hi).is_some_and(|o| o != Ordering::Greater);
+            above_lo && below_hi
+        }
+    }
+}
+
+fn values_eq(field: &str, a: &str, b: &str) -> bool {
+    compare(field, a, b) == Some(Ordering::Equal)
+}
+
+fn compare(field: &str, a: &str, b: &str) -> Option<Ordering> {
+    if VERSION_FIELDS.contains(&field) {
+        // Not every value in a "version" field is actually a parseable
+        // version (e.g. an empty driver_version when no driver is
+        // installed). Fall back to plain comparison instead of reporting
+        // "no match" so rules like "driver_version eq ''" still work.
+        if let Some(o) = compare_versions(a, b) {
+            return Some(o);
+        }
+    }
+    if let (Ok(na), Ok(nb)) = (a.parse::<f64>(), b.parse::<f64>()) {
+        return na.partial_cmp(&nb);
+    }
+    Some(a.cmp(b))
+}
+
+/// Compares two version strings segment by segment, treating `.` and `-` as
+/// equivalent separators and padding the shorter version with zeros. Returns
+/// `None` if either string isn't a valid version (a non-numeric segment, or
+/// every segment being zero).
+pub(crate) fn compare_versions(a: &str, b: &str) -> Option<Ordering> {
+    let mut va = parse_version(a)?;
+    let mut vb = parse_version(b)?;
+    let len = va.len().max(vb.len());
+    va.resize(len, 0);
+    vb.resize(len, 0);
+    Some(va.cmp(&vb))
+}
+
+fn parse_version(raw: &str) -> Option<Vec<u64>> {
+    let normalized = normalize_date_formatted(raw);
+    let segments: Vec<u64> = normalized
+        .split(['.', '-'])
+        .map(|seg| seg.parse::<u64>())
+        .collect::<Result<_, _>>()
+        .ok()?;
+
+    if segments.is_empty() || segments.iter().all(|n| *n == 0) {
+        return None;
+    }
+    Some(segments)
+}
+
+/// NVIDIA driver strings are sometimes stamped as an `mm-dd-yyyy` release
+/// date rather than a semantic version. Reorder those to `yyyy-mm-dd` so
+/// segment-by-segment comparison sorts chronologically instead of by month.
+fn normalize_date_formatted(raw: &str) -> String {
+    let parts: Vec<&str> = raw.split('-').collect();
+    let looks_like_date = parts.len() == 3
+        && parts[2].len() == 4
+        && parts.iter().all(|p| !p.is_empty() && p.chars().all(|c| c.is_ascii_digit()));
+
+    if looks_like_date {
+        format!("{}-{}-{}", parts[2], parts[0], parts[1])
+    } else {
+        raw.to_string()
+    }
+}
@@ -0,0 +1,5 @@
+//! Diagnostic analyzer: evaluates loaded rules against collected facts.
+
+pub mod conditions;
+pub mod remediations;
+pub mod rules;
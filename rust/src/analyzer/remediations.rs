@@ -0,0 +1,41 @@
+//! Loads OS-aware remediation steps for findings from the remediations
+//! knowledge file, keyed by rule id.
+
+use std::collections::HashMap;
+
+use serde::Deserialize;
+
+/// Embedded knowledge pack remediations.
+const REMEDIATIONS_JSON: &str = include_str!("../../../knowledge/remediations.json");
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct RemediationEntry {
+    #[serde(default)]
+    pub steps: Vec<String>,
+    #[serde(default)]
+    pub windows: Vec<String>,
+    #[serde(default)]
+    pub linux: Vec<String>,
+    #[serde(default)]
+    pub macos: Vec<String>,
+}
+
+/// Loads the remediation pack, keyed by rule id.
+pub fn load_remediations() -> HashMap<String, RemediationEntry> {
+    serde_json::from_str(REMEDIATIONS_JSON).expect("Failed to parse embedded remediations.json")
+}
+
+/// Resolves a rule's remediation steps for the current OS: the rule's
+/// platform-agnostic steps followed by any steps specific to
+/// `std::env::consts::OS`.
+pub fn resolve_steps(entry: &RemediationEntry) -> Vec<String> {
+    let mut steps = entry.steps.clone();
+    let platform_steps = match std::env::consts::OS {
+        "windows" => &entry.windows,
+        "linux" => &entry.linux,
+        "macos" => &entry.macos,
+        _ => return steps,
+    };
+    steps.extend(platform_steps.iter().cloned());
+    steps
+}
@@ -1,17 +1,34 @@
 //! Rule engine that loads diagnostic rules from the shared knowledge pack.
 
+use std::collections::HashMap;
+
+use crate::analyzer::conditions::{conditions_match, resolve_field, FactContext};
+use crate::analyzer::remediations::{load_remediations, resolve_steps, RemediationEntry};
 use crate::types::{Finding, Rule, RulesFile, SystemInfo, GPUInfo, DriverInfo};
 
-/// Embedded knowledge pack rules.
+/// Embedded knowledge pack rules, guaranteed to parse and always available
+/// as a fallback even when offline or the cache is missing/corrupt.
 const RULES_JSON: &str = include_str!("../../../knowledge/rules.json");
 
-/// Load diagnostic rules from the embedded knowledge pack.
-pub fn load_rules() -> Vec<Rule> {
+fn embedded_rules() -> Vec<Rule> {
     let rules_file: RulesFile = serde_json::from_str(RULES_JSON)
         .expect("Failed to parse embedded rules.json");
     rules_file.rules
 }
 
+/// Loads diagnostic rules, preferring a locally cached knowledge pack
+/// (fetched via `nvcheckup update`) over the embedded one. Pass `offline:
+/// true` to skip the cache entirely and use only the embedded pack.
+pub fn load_rules(offline: bool) -> Vec<Rule> {
+    if offline {
+        return embedded_rules();
+    }
+
+    crate::updater::load_cached_rules()
+        .map(|rules_file| rules_file.rules)
+        .unwrap_or_else(embedded_rules)
+}
+
 /// Analyze collected data against loaded rules for the given mode.
 pub fn analyze(
     system: &SystemInfo,
@@ -20,6 +37,7 @@ pub fn analyze(
     rules: &[Rule],
     mode: &str,
 ) -> Vec<Finding> {
+    let remediations = load_remediations();
     let mut findings = Vec::new();
 
     for rule in rules {
@@ -37,13 +55,13 @@ pub fn analyze(
         }
 
         // Check each rule
-        if let Some(finding) = evaluate_rule(rule, system, gpus, driver) {
+        if let Some(finding) = evaluate_rule(rule, system, gpus, driver, &remediations) {
             findings.push(finding);
         }
     }
 
     // Sort by severity: CRIT first, then WARN, then INFO
-    findings.sort_by(|a, b| severity_order(&a.severity).cmp(&severity_order(&b.severity)));
+    findings.sort_by_key(|f| severity_order(&f.severity));
 
     findings
 }
@@ -59,84 +77,146 @@ fn severity_order(s: &str) -> u8 {
 
 fn evaluate_rule(
     rule: &Rule,
-    _system: &SystemInfo,
+    system: &SystemInfo,
     gpus: &[GPUInfo],
     driver: &DriverInfo,
+    remediations: &HashMap<String, RemediationEntry>,
 ) -> Option<Finding> {
+    if !rule.conditions.is_empty() {
+        return evaluate_declarative_rule(rule, system, gpus, driver, remediations);
+    }
+
+    // A handful of rules need facts the declarative engine can't express yet
+    // (cross-process aggregation); those stay hand-coded until the field
+    // model grows to cover them.
     match rule.id.as_str() {
-        "no-nvidia-gpu" => {
-            let has_nvidia = gpus.iter().any(|g| g.is_nvidia);
-            if !has_nvidia && gpus.is_empty() {
-                return Some(make_finding(rule, "No NVIDIA GPU detected in system."));
-            }
-            None
-        }
-        "hybrid-gpu" => {
-            let nvidia_count = gpus.iter().filter(|g| g.is_nvidia).count();
-            let total = gpus.len();
-            if nvidia_count > 0 && total > nvidia_count {
-                return Some(make_finding(rule, "Both NVIDIA and integrated graphics detected."));
-            }
-            None
-        }
-        "driver-not-detected" => {
-            if driver.version.is_empty() {
-                return Some(make_finding(rule, "nvidia-smi did not return a driver version."));
+        "vram-pressure" => evaluate_vram_pressure(rule, gpus, remediations),
+        _ => None, // Unimplemented rules are skipped
+    }
+}
+
+fn evaluate_declarative_rule(
+    rule: &Rule,
+    system: &SystemInfo,
+    gpus: &[GPUInfo],
+    driver: &DriverInfo,
+    remediations: &HashMap<String, RemediationEntry>,
+) -> Option<Finding> {
+    let gpu_count = gpus.len();
+    let nvidia_gpu_count = gpus.iter().filter(|g| g.is_nvidia).count();
+    let needs_gpu = rule.conditions.iter().any(|c| c.field.starts_with("gpu."));
+
+    if needs_gpu {
+        for gpu in gpus {
+            let ctx = FactContext { system, driver, gpu: Some(gpu), gpu_count, nvidia_gpu_count };
+            if conditions_match(&rule.conditions, &ctx) {
+                return Some(make_finding(rule, &describe_match(rule, &ctx), remediations));
             }
-            None
         }
-        "nvidia-smi-missing" => {
-            // If we got no GPUs and no driver, nvidia-smi is probably missing
-            if gpus.is_empty() && driver.version.is_empty() {
-                return Some(make_finding(rule, "nvidia-smi was not found or returned no data."));
-            }
+        None
+    } else {
+        let ctx = FactContext { system, driver, gpu: None, gpu_count, nvidia_gpu_count };
+        if conditions_match(&rule.conditions, &ctx) {
+            Some(make_finding(rule, &describe_match(rule, &ctx), remediations))
+        } else {
             None
         }
-        "low-vram" => {
-            for gpu in gpus {
-                if gpu.is_nvidia && gpu.vram_total_mb > 0 && gpu.vram_total_mb < 4096 {
-                    return Some(make_finding(
-                        rule,
-                        &format!("GPU {} has {} MB VRAM (< 4 GB).", gpu.name, gpu.vram_total_mb),
-                    ));
-                }
-            }
-            None
+    }
+}
+
+/// Builds a human-readable evidence line naming each matched condition's
+/// field and observed value.
+fn describe_match(rule: &Rule, ctx: &FactContext) -> String {
+    let parts: Vec<String> = rule
+        .conditions
+        .iter()
+        .map(|c| {
+            let actual = resolve_field(&c.field, ctx).unwrap_or_else(|| "?".to_string());
+            format!("{} = {}", c.field, actual)
+        })
+        .collect();
+    format!("{} ({})", rule.title, parts.join(", "))
+}
+
+fn evaluate_vram_pressure(
+    rule: &Rule,
+    gpus: &[GPUInfo],
+    remediations: &HashMap<String, RemediationEntry>,
+) -> Option<Finding> {
+    for gpu in gpus {
+        if gpu.vram_total_mb <= 0 || gpu.processes.is_empty() {
+            continue;
         }
-        "gpu-running-hot" => {
-            for gpu in gpus {
-                if gpu.temperature_c >= 75 && gpu.temperature_c < 85 {
-                    return Some(make_finding(
-                        rule,
-                        &format!("GPU temperature is {}°C.", gpu.temperature_c),
-                    ));
-                }
-            }
-            None
+
+        let total_used_mb: i64 = gpu.processes.iter().map(|p| p.used_memory_mb).sum();
+        let usage_fraction = total_used_mb as f64 / gpu.vram_total_mb as f64;
+        if usage_fraction >= 0.9 {
+            return Some(make_finding(
+                rule,
+                &format!(
+                    "GPU {} processes are using {} MB of {} MB VRAM ({:.0}%).",
+                    gpu.name, total_used_mb, gpu.vram_total_mb, usage_fraction * 100.0,
+                ),
+                remediations,
+            ));
         }
-        "thermal-throttling" => {
-            for gpu in gpus {
-                if gpu.temperature_c >= 85 {
-                    return Some(make_finding(
-                        rule,
-                        &format!("GPU temperature is {}°C — exceeds safe limit.", gpu.temperature_c),
-                    ));
-                }
+
+        if let Some(hog) = gpu.processes.iter().max_by_key(|p| p.used_memory_mb) {
+            let hog_fraction = hog.used_memory_mb as f64 / gpu.vram_total_mb as f64;
+            if hog_fraction >= 0.75 {
+                return Some(make_finding(
+                    rule,
+                    &format!(
+                        "Process \"{}\" (pid {}) holds {} MB of GPU {}'s {} MB VRAM ({:.0}%).",
+                        hog.name, hog.pid, hog.used_memory_mb, gpu.index, gpu.vram_total_mb,
+                        hog_fraction * 100.0,
+                    ),
+                    remediations,
+                ));
             }
-            None
         }
-        _ => None, // Unimplemented rules are skipped
     }
+    None
 }
 
-fn make_finding(rule: &Rule, evidence: &str) -> Finding {
+fn make_finding(rule: &Rule, evidence: &str, remediations: &HashMap<String, RemediationEntry>) -> Finding {
+    let next_steps = remediations
+        .get(&rule.id)
+        .map(resolve_steps)
+        .unwrap_or_default();
+
     Finding {
         severity: rule.severity.clone(),
         title: rule.title.clone(),
         evidence: evidence.to_string(),
         why_it_matters: rule.description.clone(),
-        next_steps: vec![], // TODO: Load from remediations.json
+        next_steps,
         confidence: rule.base_confidence,
         category: rule.category.clone(),
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn empty_driver_version_trips_driver_not_detected_and_smi_missing() {
+        let system = SystemInfo {
+            os_name: "linux".to_string(),
+            os_version: "unknown".to_string(),
+            architecture: "x86_64".to_string(),
+            cpu_model: "unknown".to_string(),
+            ram_total_mb: 16384,
+            hostname: "test-host".to_string(),
+        };
+        let driver = DriverInfo { version: String::new(), cuda_version: String::new() };
+        let rules = embedded_rules();
+
+        let findings = analyze(&system, &[], &driver, &rules, "full");
+        let titles: Vec<&str> = findings.iter().map(|f| f.title.as_str()).collect();
+
+        assert!(titles.contains(&"NVIDIA Driver Not Detected"));
+        assert!(titles.contains(&"nvidia-smi Not Found"));
+    }
+}
@@ -1,9 +1,132 @@
-//! GPU and driver information collector via nvidia-smi.
+//! GPU and driver information collector.
+//!
+//! When built with the `nvml` feature, prefers the NVIDIA Management
+//! Library (NVML) for rich, structured telemetry — memory, utilization,
+//! power, clocks, fan speed, PCI info — and falls back to parsing
+//! `nvidia-smi` output on systems where NVML isn't installed or fails to
+//! initialize. Without the feature, `nvidia-smi` is always used.
 
 use crate::types::{GPUInfo, DriverInfo};
+#[cfg(feature = "nvml")]
+use crate::types::{GpuProcess, GpuProcessType};
+#[cfg(feature = "nvml")]
+use crate::collector::processes::resolve_process_name;
 use crate::collector::system::run_command;
 
 pub fn collect_gpu_info() -> (Vec<GPUInfo>, DriverInfo) {
+    #[cfg(feature = "nvml")]
+    {
+        if let Some(result) = collect_via_nvml() {
+            return result;
+        }
+    }
+
+    collect_via_nvidia_smi()
+}
+
+#[cfg(feature = "nvml")]
+fn collect_via_nvml() -> Option<(Vec<GPUInfo>, DriverInfo)> {
+    use nvml_wrapper::Nvml;
+    use nvml_wrapper::enum_wrappers::device::Clock;
+
+    let nvml = Nvml::init().ok()?;
+    let count = nvml.device_count().ok()?;
+
+    let driver = DriverInfo {
+        version: nvml.sys_driver_version().unwrap_or_default(),
+        cuda_version: nvml
+            .sys_cuda_driver_version()
+            .map(|v| format!("{}.{}", v / 1000, (v % 1000) / 10))
+            .unwrap_or_default(),
+    };
+
+    let mut gpus = Vec::new();
+    for i in 0..count {
+        let device = match nvml.device_by_index(i) {
+            Ok(d) => d,
+            Err(_) => continue,
+        };
+
+        let memory = device.memory_info().ok();
+        let utilization = device.utilization_rates().ok();
+        let pci = device.pci_info().ok();
+        // NVML packs vendor and device IDs into a single 32-bit field:
+        // vendor ID in the low 16 bits, device ID in the high 16 bits.
+        let raw_pci_device_id = pci.as_ref().map(|p| p.pci_device_id).unwrap_or(0);
+        let processes = collect_gpu_processes(&device);
+
+        gpus.push(GPUInfo {
+            index: i as usize,
+            name: device.name().unwrap_or_else(|_| "Unknown".to_string()),
+            vendor: "NVIDIA".to_string(),
+            driver_version: driver.version.clone(),
+            vram_total_mb: memory.as_ref().map(|m| (m.total / 1024 / 1024) as i64).unwrap_or(0),
+            vram_used_mb: memory.as_ref().map(|m| (m.used / 1024 / 1024) as i64).unwrap_or(0),
+            vram_free_mb: memory.as_ref().map(|m| (m.free / 1024 / 1024) as i64).unwrap_or(0),
+            temperature_c: device
+                .temperature(nvml_wrapper::enum_wrappers::device::TemperatureSensor::Gpu)
+                .map(|t| t as i32)
+                .unwrap_or(0),
+            gpu_utilization_pct: utilization.as_ref().map(|u| u.gpu).unwrap_or(0),
+            mem_utilization_pct: utilization.as_ref().map(|u| u.memory).unwrap_or(0),
+            power_usage_w: device.power_usage().map(|m| m as f64 / 1000.0).unwrap_or(0.0),
+            power_limit_w: device
+                .power_management_limit()
+                .map(|m| m as f64 / 1000.0)
+                .unwrap_or(0.0),
+            clock_graphics_mhz: device.clock_info(Clock::Graphics).unwrap_or(0),
+            clock_sm_mhz: device.clock_info(Clock::SM).unwrap_or(0),
+            clock_memory_mhz: device.clock_info(Clock::Memory).unwrap_or(0),
+            fan_speed_pct: device.fan_speed(0).unwrap_or(0),
+            pci_bus_id: pci.as_ref().map(|p| p.bus_id.clone()).unwrap_or_default(),
+            pci_device_id: (raw_pci_device_id >> 16) & 0xFFFF,
+            pci_vendor_id: raw_pci_device_id & 0xFFFF,
+            is_nvidia: true,
+            processes,
+        });
+    }
+
+    Some((gpus, driver))
+}
+
+#[cfg(feature = "nvml")]
+fn collect_gpu_processes(device: &nvml_wrapper::Device) -> Vec<GpuProcess> {
+    let mut processes = Vec::new();
+
+    if let Ok(compute) = device.running_compute_processes() {
+        for p in compute {
+            processes.push(GpuProcess {
+                pid: p.pid,
+                name: resolve_process_name(p.pid),
+                used_memory_mb: used_gpu_memory_mb(&p.used_gpu_memory),
+                process_type: GpuProcessType::Compute,
+            });
+        }
+    }
+
+    if let Ok(graphics) = device.running_graphics_processes() {
+        for p in graphics {
+            processes.push(GpuProcess {
+                pid: p.pid,
+                name: resolve_process_name(p.pid),
+                used_memory_mb: used_gpu_memory_mb(&p.used_gpu_memory),
+                process_type: GpuProcessType::Graphics,
+            });
+        }
+    }
+
+    processes
+}
+
+#[cfg(feature = "nvml")]
+fn used_gpu_memory_mb(used: &nvml_wrapper::enums::device::UsedGpuMemory) -> i64 {
+    match used {
+        nvml_wrapper::enums::device::UsedGpuMemory::Used(bytes) => (*bytes / 1024 / 1024) as i64,
+        nvml_wrapper::enums::device::UsedGpuMemory::Unavailable => 0,
+    }
+}
+
+fn collect_via_nvidia_smi() -> (Vec<GPUInfo>, DriverInfo) {
     let mut gpus = Vec::new();
     let mut driver = DriverInfo {
         version: String::new(),
@@ -37,18 +160,28 @@ pub fn collect_gpu_info() -> (Vec<GPUInfo>, DriverInfo) {
                     vendor: "NVIDIA".to_string(),
                     driver_version: driver_ver,
                     vram_total_mb: vram,
+                    vram_used_mb: 0,
+                    vram_free_mb: 0,
                     temperature_c: temp,
+                    gpu_utilization_pct: 0,
+                    mem_utilization_pct: 0,
+                    power_usage_w: 0.0,
+                    power_limit_w: 0.0,
+                    clock_graphics_mhz: 0,
+                    clock_sm_mhz: 0,
+                    clock_memory_mhz: 0,
+                    fan_speed_pct: 0,
+                    pci_bus_id: String::new(),
+                    pci_device_id: 0,
+                    pci_vendor_id: 0,
                     is_nvidia: true,
+                    processes: Vec::new(),
                 });
             }
         }
     }
 
     // Query CUDA version
-    let cuda_output = run_command(
-        "nvidia-smi",
-        &["--query-gpu=driver_version", "--format=csv,noheader"],
-    );
     // Full nvidia-smi output usually has CUDA version in header
     let smi_output = run_command("nvidia-smi", &[]);
     if let Some(output) = smi_output {
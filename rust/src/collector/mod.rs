@@ -0,0 +1,5 @@
+//! Collectors that gather system and GPU facts from the host.
+
+pub mod gpu;
+pub mod processes;
+pub mod system;
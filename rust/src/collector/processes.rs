@@ -0,0 +1,25 @@
+//! Resolves GPU compute/graphics process PIDs to friendly process names by
+//! joining against the OS process table.
+
+use crate::collector::system::run_command;
+
+/// Look up the process name for a PID, falling back to `"unknown"` when the
+/// process has already exited or the platform command fails.
+#[cfg_attr(not(feature = "nvml"), allow(dead_code))]
+pub fn resolve_process_name(pid: u32) -> String {
+    let name = if cfg!(target_os = "windows") {
+        run_command("tasklist", &["/FI", &format!("PID eq {}", pid), "/FO", "CSV", "/NH"])
+            // With no matching PID, tasklist exits successfully and prints an
+            // "INFO: No tasks ..." line instead of a CSV row.
+            .filter(|out| !out.starts_with("INFO:"))
+            .and_then(|out| out.split(',').next().map(|s| s.trim_matches('"').to_string()))
+    } else {
+        run_command("ps", &["-p", &pid.to_string(), "-o", "comm="])
+            .map(|out| out.trim().to_string())
+    };
+
+    match name {
+        Some(n) if !n.is_empty() => n,
+        _ => "unknown".to_string(),
+    }
+}
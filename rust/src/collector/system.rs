@@ -1,56 +1,37 @@
 //! System information collector.
+//!
+//! Uses the `sysinfo` crate for uniform access to RAM, CPU, and OS facts
+//! across Linux, Windows, and macOS instead of shelling out to
+//! platform-specific commands.
 
 use crate::types::SystemInfo;
 use std::process::Command;
+use sysinfo::System;
 
 pub fn collect_system_info() -> SystemInfo {
-    let os_name = std::env::consts::OS.to_string();
-    let os_version = get_os_version();
-    let arch = std::env::consts::ARCH.to_string();
-    let cpu_model = get_cpu_model();
-    let hostname = get_hostname();
+    let mut sys = System::new_all();
+    sys.refresh_all();
+
+    let os_name = System::name().unwrap_or_else(|| std::env::consts::OS.to_string());
+    let os_version = System::os_version().unwrap_or_else(|| "unknown".to_string());
+    let cpu_model = sys
+        .cpus()
+        .first()
+        .map(|cpu| cpu.brand().to_string())
+        .filter(|s| !s.is_empty())
+        .unwrap_or_else(|| "unknown".to_string());
+    let hostname = System::host_name().unwrap_or_else(|| "unknown".to_string());
 
     SystemInfo {
         os_name,
         os_version,
-        architecture: arch,
+        architecture: std::env::consts::ARCH.to_string(),
         cpu_model,
-        ram_total_mb: 0,
+        ram_total_mb: (sys.total_memory() / 1024 / 1024) as i64,
         hostname,
     }
 }
 
-fn get_os_version() -> String {
-    if cfg!(target_os = "windows") {
-        run_command("cmd", &["/c", "ver"])
-            .unwrap_or_else(|| "unknown".to_string())
-    } else {
-        run_command("uname", &["-r"])
-            .unwrap_or_else(|| "unknown".to_string())
-    }
-}
-
-fn get_cpu_model() -> String {
-    if cfg!(target_os = "windows") {
-        run_command("powershell", &["-NoProfile", "-Command",
-            "(Get-CimInstance Win32_Processor).Name"])
-            .unwrap_or_else(|| "unknown".to_string())
-    } else {
-        run_command("sh", &["-c", "grep 'model name' /proc/cpuinfo | head -1 | cut -d: -f2"])
-            .map(|s| s.trim().to_string())
-            .unwrap_or_else(|| "unknown".to_string())
-    }
-}
-
-fn get_hostname() -> String {
-    if cfg!(target_os = "windows") {
-        run_command("hostname", &[])
-    } else {
-        run_command("hostname", &[])
-    }
-    .unwrap_or_else(|| "unknown".to_string())
-}
-
 pub fn run_command(cmd: &str, args: &[&str]) -> Option<String> {
     Command::new(cmd)
         .args(args)
@@ -64,3 +45,16 @@ pub fn run_command(cmd: &str, args: &[&str]) -> Option<String> {
             }
         })
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn ram_total_mb_is_plausible_for_bytes_not_kib() {
+        let info = collect_system_info();
+        // sysinfo::System::total_memory() returns bytes as of 0.30; if a future
+        // version reverts to KiB this would read ~1000x too small and fail.
+        assert!(info.ram_total_mb > 100, "ram_total_mb implausibly small: {}", info.ram_total_mb);
+    }
+}
@@ -4,11 +4,13 @@
 mod types;
 mod collector;
 mod analyzer;
+mod monitor;
 mod report;
+mod updater;
 
 use std::env;
 use std::process;
-use std::time::Instant;
+use std::time::{Duration, Instant};
 
 const VERSION: &str = "0.2.0";
 const DISCLAIMER: &str = "NVCheckup is an unofficial community tool, not affiliated with or endorsed by NVIDIA Corporation.";
@@ -23,6 +25,8 @@ fn main() {
 
     match args[1].as_str() {
         "run" => run_cmd(&args[2..]),
+        "monitor" => monitor_cmd(&args[2..]),
+        "update" => update_cmd(&args[2..]),
         "version" | "--version" | "-v" => {
             println!("NVCheckup v{}", VERSION);
             println!("{}", DISCLAIMER);
@@ -38,18 +42,23 @@ fn main() {
 
 fn run_cmd(args: &[String]) {
     let mut mode = "full".to_string();
-    let mut verbose = false;
+    let mut _verbose = false;
+    let mut format = "text".to_string();
+    let mut offline = false;
 
     let mut i = 0;
     while i < args.len() {
         match args[i].as_str() {
-            "--mode" => {
-                if i + 1 < args.len() {
-                    mode = args[i + 1].clone();
-                    i += 1;
-                }
+            "--mode" if i + 1 < args.len() => {
+                mode = args[i + 1].clone();
+                i += 1;
             }
-            "--verbose" => verbose = true,
+            "--format" if i + 1 < args.len() => {
+                format = args[i + 1].clone();
+                i += 1;
+            }
+            "--offline" => offline = true,
+            "--verbose" => _verbose = true,
             _ => {}
         }
         i += 1;
@@ -64,34 +73,102 @@ fn run_cmd(args: &[String]) {
         }
     }
 
-    println!();
-    println!("  NVCheckup v{} (Rust)", VERSION);
-    println!("  {}", DISCLAIMER);
-    println!();
+    // Validate format
+    match format.as_str() {
+        "text" | "json" => {}
+        other => {
+            eprintln!("Invalid format: {}. Use: text, json", other);
+            process::exit(3);
+        }
+    }
+
+    let quiet = format == "json";
+
+    if !quiet {
+        println!();
+        println!("  NVCheckup v{} (Rust)", VERSION);
+        println!("  {}", DISCLAIMER);
+        println!();
+    }
 
     let start = Instant::now();
 
     // Collect
-    println!("[1/3] Collecting system and GPU information...");
+    if !quiet {
+        println!("[1/3] Collecting system and GPU information...");
+    }
     let system = collector::system::collect_system_info();
     let (gpus, driver) = collector::gpu::collect_gpu_info();
 
     // Analyze
-    println!("[2/3] Analyzing results...");
-    let rules = analyzer::rules::load_rules();
+    if !quiet {
+        println!("[2/3] Analyzing results...");
+    }
+    let rules = analyzer::rules::load_rules(offline);
     let findings = analyzer::rules::analyze(&system, &gpus, &driver, &rules, &mode);
 
     // Report
-    println!("[3/3] Generating report...");
+    if !quiet {
+        println!("[3/3] Generating report...");
+    }
     let elapsed = start.elapsed().as_secs_f64();
 
-    let report_text = report::text::generate(
-        &system, &gpus, &driver, &findings, &mode, elapsed,
+    let report_out = match format.as_str() {
+        "json" => report::json::generate(&system, &gpus, &driver, &findings, &mode, elapsed),
+        _ => report::text::generate(&system, &gpus, &driver, &findings, &mode, elapsed),
+    };
+
+    if !quiet {
+        println!();
+    }
+    println!("{}", report_out);
+
+    // Exit code
+    let has_crit = findings.iter().any(|f| f.severity == "CRIT");
+    let has_warn = findings.iter().any(|f| f.severity == "WARN");
+    if has_crit {
+        process::exit(2);
+    } else if has_warn {
+        process::exit(1);
+    }
+}
+
+fn monitor_cmd(args: &[String]) {
+    let mut interval_secs: f64 = 2.0;
+    let mut duration_secs: f64 = 60.0;
+
+    let mut i = 0;
+    while i < args.len() {
+        match args[i].as_str() {
+            "--interval" if i + 1 < args.len() => {
+                interval_secs = args[i + 1].parse().unwrap_or(interval_secs);
+                i += 1;
+            }
+            "--duration" if i + 1 < args.len() => {
+                duration_secs = args[i + 1].parse().unwrap_or(duration_secs);
+                i += 1;
+            }
+            _ => {}
+        }
+        i += 1;
+    }
+
+    println!();
+    println!("  NVCheckup v{} (Rust) — monitor mode", VERSION);
+    println!("  {}", DISCLAIMER);
+    println!("  Sampling every {:.1}s for {:.0}s. Press Ctrl+C to stop early.", interval_secs, duration_secs);
+    println!();
+
+    let findings = monitor::run(
+        Duration::from_secs_f64(interval_secs.max(0.1)),
+        Duration::from_secs_f64(duration_secs.max(interval_secs)),
     );
+
+    println!();
+    println!("== MONITOR FINDINGS ==");
     println!();
-    println!("{}", report_text);
+    print!("{}", report::text::render_findings(&findings));
 
-    // Exit code
     let has_crit = findings.iter().any(|f| f.severity == "CRIT");
     let has_warn = findings.iter().any(|f| f.severity == "WARN");
     if has_crit {
@@ -101,6 +178,33 @@ fn run_cmd(args: &[String]) {
     }
 }
 
+fn update_cmd(args: &[String]) {
+    let mut url = updater::DEFAULT_RULES_URL.to_string();
+
+    let mut i = 0;
+    while i < args.len() {
+        if args[i] == "--url" && i + 1 < args.len() {
+            url = args[i + 1].clone();
+            i += 1;
+        }
+        i += 1;
+    }
+
+    println!("Fetching knowledge pack from {}...", url);
+    match updater::update(&url) {
+        Ok(updater::UpdateOutcome::Updated { from, to }) => {
+            println!("Updated rules pack: {} -> {}", from, to);
+        }
+        Ok(updater::UpdateOutcome::UpToDate { version }) => {
+            println!("Already up to date (version {}).", version);
+        }
+        Err(e) => {
+            eprintln!("Update failed: {}", e);
+            process::exit(1);
+        }
+    }
+}
+
 fn print_usage() {
     println!(
         r#"NVCheckup v{} — Cross-platform NVIDIA Diagnostic Tool (Rust)
@@ -111,16 +215,30 @@ Usage:
 
 Commands:
   run         Run diagnostics and generate a report
+  monitor     Sample GPU telemetry over time and report trend-based findings
+  update      Fetch the latest knowledge pack (rules.json) and cache it locally
   version     Show version information
 
 Run Flags:
   --mode      Diagnostic mode: gaming, ai, creator, streaming, full (default: full)
+  --format    Output format: text, json (default: text)
+  --offline   Skip the cached knowledge pack and use the embedded one
   --verbose   Enable verbose output
 
+Monitor Flags:
+  --interval  Seconds between samples (default: 2)
+  --duration  Total seconds to sample for (default: 60)
+
+Update Flags:
+  --url       Knowledge pack URL to fetch (default: the official rules.json)
+
 Examples:
   nvcheckup run --mode gaming
   nvcheckup run --mode ai
   nvcheckup run --mode full
+  nvcheckup run --format json
+  nvcheckup monitor --interval 1 --duration 120
+  nvcheckup update
 "#,
         VERSION, DISCLAIMER
     );
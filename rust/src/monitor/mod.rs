@@ -0,0 +1,199 @@
+//! Continuous `monitor` mode: samples GPU telemetry over time and detects
+//! trends a single snapshot can't catch (sustained thermal throttling,
+//! utilization saturation, power-limit clamping, climbing VRAM pressure).
+
+use std::collections::VecDeque;
+use std::thread;
+use std::time::{Duration, Instant};
+
+use crate::collector::gpu;
+use crate::types::Finding;
+
+/// Consecutive samples at/above a threshold before a trend is reported,
+/// so a single noisy reading doesn't trigger a finding.
+const STREAK_THRESHOLD: usize = 3;
+const THERMAL_THROTTLE_TEMP_C: i32 = 85;
+const UTILIZATION_SATURATION_PCT: u32 = 99;
+const POWER_CLAMP_FRACTION: f64 = 0.97;
+const VRAM_CLIMB_FRACTION: f64 = 0.1;
+
+struct Sample {
+    temperature_c: i32,
+    gpu_utilization_pct: u32,
+    power_usage_w: f64,
+    power_limit_w: f64,
+    vram_used_mb: i64,
+    clock_graphics_mhz: u32,
+}
+
+struct GpuHistory {
+    index: usize,
+    name: String,
+    vram_total_mb: i64,
+    samples: VecDeque<Sample>,
+}
+
+/// Polls GPU telemetry every `interval` until `duration` elapses, printing a
+/// live-updating summary line, then returns trend-based findings.
+pub fn run(interval: Duration, duration: Duration) -> Vec<Finding> {
+    let capacity = ((duration.as_secs_f64() / interval.as_secs_f64()).ceil() as usize).max(1);
+    let mut histories: Vec<GpuHistory> = Vec::new();
+
+    let start = Instant::now();
+    let mut tick = 0u64;
+    while start.elapsed() < duration {
+        let (gpus, _driver) = gpu::collect_gpu_info();
+
+        for g in &gpus {
+            let history = match histories.iter_mut().find(|h| h.index == g.index) {
+                Some(h) => h,
+                None => {
+                    histories.push(GpuHistory {
+                        index: g.index,
+                        name: g.name.clone(),
+                        vram_total_mb: g.vram_total_mb,
+                        samples: VecDeque::with_capacity(capacity),
+                    });
+                    histories.last_mut().unwrap()
+                }
+            };
+
+            if history.samples.len() == capacity {
+                history.samples.pop_front();
+            }
+            history.samples.push_back(Sample {
+                temperature_c: g.temperature_c,
+                gpu_utilization_pct: g.gpu_utilization_pct,
+                power_usage_w: g.power_usage_w,
+                power_limit_w: g.power_limit_w,
+                vram_used_mb: g.vram_used_mb,
+                clock_graphics_mhz: g.clock_graphics_mhz,
+            });
+        }
+
+        print_live_summary(tick, &histories);
+
+        tick += 1;
+        thread::sleep(interval);
+    }
+    println!();
+
+    histories.iter().flat_map(evaluate_trends).collect()
+}
+
+fn print_live_summary(tick: u64, histories: &[GpuHistory]) {
+    let mut line = format!("\r[monitor] sample {:>4}  ", tick);
+    for h in histories {
+        if let Some(latest) = h.samples.back() {
+            line.push_str(&format!(
+                "GPU{}: {}°C {}% {:.0}W  ",
+                h.index, latest.temperature_c, latest.gpu_utilization_pct, latest.power_usage_w,
+            ));
+        }
+    }
+    print!("{}", line);
+    use std::io::Write;
+    let _ = std::io::stdout().flush();
+}
+
+fn evaluate_trends(history: &GpuHistory) -> Vec<Finding> {
+    let mut findings = Vec::new();
+    let samples = &history.samples;
+    if samples.is_empty() {
+        return findings;
+    }
+
+    let throttle_streak = trailing_streak(samples, |s| s.temperature_c >= THERMAL_THROTTLE_TEMP_C);
+    if throttle_streak >= STREAK_THRESHOLD {
+        let max_clock = samples.iter().map(|s| s.clock_graphics_mhz).max().unwrap_or(0);
+        let latest_clock = samples.back().map(|s| s.clock_graphics_mhz).unwrap_or(0);
+        let clocks_dropped = max_clock > 0 && latest_clock < max_clock * 9 / 10;
+
+        findings.push(Finding {
+            severity: "CRIT".to_string(),
+            title: "Sustained Thermal Throttling".to_string(),
+            evidence: format!(
+                "GPU {} ({}) held >= {}\u{b0}C for {} consecutive samples.",
+                history.index, history.name, THERMAL_THROTTLE_TEMP_C, throttle_streak,
+            ),
+            why_it_matters: if clocks_dropped {
+                "Clocks dropped while at the thermal limit, confirming throttling is costing performance.".to_string()
+            } else {
+                "Running at the thermal limit risks throttling and accelerates wear even if clocks haven't dropped yet.".to_string()
+            },
+            next_steps: vec![
+                "Improve case/GPU airflow and check fan curves.".to_string(),
+                "Reduce workload intensity or add a cooldown period.".to_string(),
+            ],
+            confidence: if clocks_dropped { 90 } else { 70 },
+            category: "thermal".to_string(),
+        });
+    }
+
+    let saturation_streak = trailing_streak(samples, |s| s.gpu_utilization_pct >= UTILIZATION_SATURATION_PCT);
+    if saturation_streak >= STREAK_THRESHOLD {
+        findings.push(Finding {
+            severity: "INFO".to_string(),
+            title: "GPU Utilization Saturated".to_string(),
+            evidence: format!(
+                "GPU {} ({}) held >= {}% utilization for {} consecutive samples.",
+                history.index, history.name, UTILIZATION_SATURATION_PCT, saturation_streak,
+            ),
+            why_it_matters: "The GPU is compute-bound; further speedups will require faster hardware, not configuration changes.".to_string(),
+            next_steps: vec![],
+            confidence: 80,
+            category: "performance".to_string(),
+        });
+    }
+
+    let power_clamp_streak = trailing_streak(samples, |s| {
+        s.power_limit_w > 0.0 && s.power_usage_w >= s.power_limit_w * POWER_CLAMP_FRACTION
+    });
+    if power_clamp_streak >= STREAK_THRESHOLD {
+        findings.push(Finding {
+            severity: "WARN".to_string(),
+            title: "Power Limit Clamping".to_string(),
+            evidence: format!(
+                "GPU {} ({}) held power draw at >= {:.0}% of its power limit for {} consecutive samples.",
+                history.index, history.name, POWER_CLAMP_FRACTION * 100.0, power_clamp_streak,
+            ),
+            why_it_matters: "The GPU is capping clocks to stay under its power limit, leaving performance on the table.".to_string(),
+            next_steps: vec!["Raise the power limit if thermals and PSU headroom allow it.".to_string()],
+            confidence: 75,
+            category: "power".to_string(),
+        });
+    }
+
+    if history.vram_total_mb > 0 && samples.len() >= STREAK_THRESHOLD {
+        let first_used = samples.front().map(|s| s.vram_used_mb).unwrap_or(0);
+        let last_used = samples.back().map(|s| s.vram_used_mb).unwrap_or(0);
+        let climbed = last_used - first_used;
+        let climbing = climbed as f64 >= history.vram_total_mb as f64 * VRAM_CLIMB_FRACTION
+            && is_non_decreasing(samples, |s| s.vram_used_mb);
+
+        if climbing {
+            findings.push(Finding {
+                severity: "WARN".to_string(),
+                title: "VRAM Usage Steadily Climbing".to_string(),
+                evidence: format!(
+                    "GPU {} ({}) VRAM usage rose from {} MB to {} MB of {} MB over the monitoring window.",
+                    history.index, history.name, first_used, last_used, history.vram_total_mb,
+                ),
+                why_it_matters: "Steadily climbing VRAM usage often indicates a memory leak and risks allocation failures if it reaches full.".to_string(),
+                next_steps: vec!["Watch for out-of-memory errors and restart the offending process if usage keeps climbing.".to_string()],
+                confidence: 65,
+                category: "hardware".to_string(),
+            });
+        }
+    }
+
+    findings
+}
+
+fn trailing_streak(samples: &VecDeque<Sample>, pred: impl Fn(&Sample) -> bool) -> usize {
+    samples.iter().rev().take_while(|s| pred(s)).count()
+}
+
+fn is_non_decreasing(samples: &VecDeque<Sample>, field: impl Fn(&Sample) -> i64) -> bool {
+    samples.iter().map(&field).collect::<Vec<_>>().windows(2).all(|w| w[1] >= w[0])
+}
@@ -0,0 +1,41 @@
+//! JSON report generator for scripting, CI, and dashboards.
+
+use serde::Serialize;
+
+use crate::types::{SystemInfo, GPUInfo, DriverInfo, Finding};
+
+const VERSION: &str = "0.2.0";
+
+#[derive(Debug, Serialize)]
+pub struct Report<'a> {
+    pub version: &'a str,
+    pub mode: &'a str,
+    pub runtime_secs: f64,
+    pub system: &'a SystemInfo,
+    pub gpus: &'a [GPUInfo],
+    pub driver: &'a DriverInfo,
+    pub findings: &'a [Finding],
+}
+
+/// Serializes the full report as pretty-printed JSON, suitable for piping
+/// straight into `jq` or a dashboard ingester.
+pub fn generate(
+    system: &SystemInfo,
+    gpus: &[GPUInfo],
+    driver: &DriverInfo,
+    findings: &[Finding],
+    mode: &str,
+    runtime_secs: f64,
+) -> String {
+    let report = Report {
+        version: VERSION,
+        mode,
+        runtime_secs,
+        system,
+        gpus,
+        driver,
+        findings,
+    };
+
+    serde_json::to_string_pretty(&report).expect("Failed to serialize report")
+}
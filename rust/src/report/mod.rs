@@ -0,0 +1,4 @@
+//! Report formatters that render collected facts and findings for the user.
+
+pub mod json;
+pub mod text;
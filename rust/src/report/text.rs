@@ -1,6 +1,6 @@
 //! Text report generator.
 
-use crate::types::{SystemInfo, GPUInfo, DriverInfo, Finding};
+use crate::types::{SystemInfo, GPUInfo, DriverInfo, Finding, GpuProcess, GpuProcessType};
 
 const DISCLAIMER: &str = "NVCheckup is an unofficial community tool, not affiliated with or endorsed by NVIDIA Corporation.";
 
@@ -17,7 +17,7 @@ pub fn generate(
 
     out.push_str(&line);
     out.push('\n');
-    out.push_str(&format!("  NVCheckup v0.2.0 — NVIDIA Diagnostic Report (Rust)\n"));
+    out.push_str("  NVCheckup v0.2.0 — NVIDIA Diagnostic Report (Rust)\n");
     out.push_str(&format!("  {}\n", DISCLAIMER));
     out.push_str(&line);
     out.push('\n');
@@ -32,6 +32,7 @@ pub fn generate(
     out.push_str(&format!("  OS:           {} {}\n", system.os_name, system.os_version));
     out.push_str(&format!("  Architecture: {}\n", system.architecture));
     out.push_str(&format!("  CPU:          {}\n", system.cpu_model));
+    out.push_str(&format!("  RAM:          {} MB\n", system.ram_total_mb));
     out.push_str(&line);
     out.push('\n');
 
@@ -57,23 +58,32 @@ pub fn generate(
     out.push_str(&line);
     out.push('\n');
 
-    // Findings
-    out.push_str("\n== FINDINGS ==\n\n");
-    if findings.is_empty() {
-        out.push_str("  No issues detected.\n");
-    } else {
-        let crit = findings.iter().filter(|f| f.severity == "CRIT").count();
-        let warn = findings.iter().filter(|f| f.severity == "WARN").count();
-        let info = findings.iter().filter(|f| f.severity == "INFO").count();
-        out.push_str(&format!("  Total: {} CRITICAL, {} WARNING, {} INFO\n\n", crit, warn, info));
-
-        for (i, f) in findings.iter().enumerate() {
-            out.push_str(&format!("  [{}] #{}: {} (confidence: {}%)\n", f.severity, i + 1, f.title, f.confidence));
-            out.push_str(&format!("    Evidence:     {}\n", f.evidence));
-            out.push_str(&format!("    Why:          {}\n", f.why_it_matters));
-            out.push('\n');
+    // Top GPU processes
+    let mut processes: Vec<(&GPUInfo, &GpuProcess)> = gpus
+        .iter()
+        .flat_map(|gpu| gpu.processes.iter().map(move |p| (gpu, p)))
+        .collect();
+    if !processes.is_empty() {
+        processes.sort_by_key(|(_, p)| std::cmp::Reverse(p.used_memory_mb));
+        out.push_str("\n== TOP GPU PROCESSES ==\n\n");
+        for (gpu, proc) in &processes {
+            let kind = match proc.process_type {
+                GpuProcessType::Compute => "Compute",
+                GpuProcessType::Graphics => "Graphics",
+                GpuProcessType::Unknown => "Unknown",
+            };
+            out.push_str(&format!(
+                "  [GPU {}] {} (pid {}, {})  {} MB\n",
+                gpu.index, proc.name, proc.pid, kind, proc.used_memory_mb,
+            ));
         }
+        out.push_str(&line);
+        out.push('\n');
     }
+
+    // Findings
+    out.push_str("\n== FINDINGS ==\n\n");
+    out.push_str(&render_findings(findings));
     out.push_str(&line);
     out.push('\n');
 
@@ -89,3 +99,35 @@ pub fn generate(
 
     out
 }
+
+/// Renders the "Total: N CRITICAL, ..." summary line and each finding's
+/// detail block. Shared by the one-shot report and `monitor`'s end-of-run
+/// summary.
+pub fn render_findings(findings: &[Finding]) -> String {
+    let mut out = String::new();
+
+    if findings.is_empty() {
+        out.push_str("  No issues detected.\n");
+        return out;
+    }
+
+    let crit = findings.iter().filter(|f| f.severity == "CRIT").count();
+    let warn = findings.iter().filter(|f| f.severity == "WARN").count();
+    let info = findings.iter().filter(|f| f.severity == "INFO").count();
+    out.push_str(&format!("  Total: {} CRITICAL, {} WARNING, {} INFO\n\n", crit, warn, info));
+
+    for (i, f) in findings.iter().enumerate() {
+        out.push_str(&format!("  [{}] #{}: {} (confidence: {}%)\n", f.severity, i + 1, f.title, f.confidence));
+        out.push_str(&format!("    Evidence:     {}\n", f.evidence));
+        out.push_str(&format!("    Why:          {}\n", f.why_it_matters));
+        if !f.next_steps.is_empty() {
+            out.push_str("    Next Steps:\n");
+            for step in &f.next_steps {
+                out.push_str(&format!("      - {}\n", step));
+            }
+        }
+        out.push('\n');
+    }
+
+    out
+}
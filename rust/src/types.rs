@@ -19,8 +19,37 @@ pub struct GPUInfo {
     pub vendor: String,
     pub driver_version: String,
     pub vram_total_mb: i64,
+    pub vram_used_mb: i64,
+    pub vram_free_mb: i64,
     pub temperature_c: i32,
+    pub gpu_utilization_pct: u32,
+    pub mem_utilization_pct: u32,
+    pub power_usage_w: f64,
+    pub power_limit_w: f64,
+    pub clock_graphics_mhz: u32,
+    pub clock_sm_mhz: u32,
+    pub clock_memory_mhz: u32,
+    pub fan_speed_pct: u32,
+    pub pci_bus_id: String,
+    pub pci_device_id: u32,
+    pub pci_vendor_id: u32,
     pub is_nvidia: bool,
+    pub processes: Vec<GpuProcess>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum GpuProcessType {
+    Compute,
+    Graphics,
+    Unknown,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GpuProcess {
+    pub pid: u32,
+    pub name: String,
+    pub used_memory_mb: i64,
+    pub process_type: GpuProcessType,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -52,6 +81,31 @@ pub struct Rule {
     #[serde(default)]
     pub platform: Option<String>,
     pub description: String,
+    #[serde(default)]
+    pub conditions: Vec<Condition>,
+}
+
+/// A single condition evaluated against a collected field, modeled on
+/// Chromium's GPU control list entries (e.g. `driver_version between
+/// 535.0 and 535.129.03`).
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Condition {
+    pub field: String,
+    pub op: ConditionOp,
+    #[serde(default)]
+    pub value: Option<String>,
+    #[serde(default)]
+    pub value2: Option<String>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ConditionOp {
+    Eq,
+    Lt,
+    Gt,
+    Between,
+    Any,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
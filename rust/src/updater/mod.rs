@@ -0,0 +1,71 @@
+//! Fetches a newer `rules.json` knowledge pack from a configurable URL,
+//! caches it locally, and lets `analyzer::rules` load cache-over-embedded.
+//! The embedded pack is always the fallback when offline or when the
+//! cached/remote file fails to parse, so a bad download can never break
+//! diagnostics.
+
+use std::fs;
+use std::path::PathBuf;
+
+use crate::analyzer::conditions::compare_versions;
+use crate::types::RulesFile;
+
+pub const DEFAULT_RULES_URL: &str =
+    "https://raw.githubusercontent.com/thatcooperguy/NVCheckup/main/knowledge/rules.json";
+
+#[derive(Debug)]
+pub enum UpdateOutcome {
+    Updated { from: String, to: String },
+    UpToDate { version: String },
+}
+
+/// Platform-appropriate cache directory for the knowledge pack, e.g.
+/// `~/.cache/nvcheckup` on Linux or `%LOCALAPPDATA%\nvcheckup` on Windows.
+pub fn cache_dir() -> PathBuf {
+    dirs::cache_dir()
+        .unwrap_or_else(std::env::temp_dir)
+        .join("nvcheckup")
+}
+
+fn cached_rules_path() -> PathBuf {
+    cache_dir().join("rules.json")
+}
+
+/// Reads and parses the cached knowledge pack, if present and valid.
+pub fn load_cached_rules() -> Option<RulesFile> {
+    let contents = fs::read_to_string(cached_rules_path()).ok()?;
+    serde_json::from_str(&contents).ok()
+}
+
+/// Fetches the rules pack from `url`, validates it parses as a `RulesFile`,
+/// and writes it to the cache dir only if it's newer than what's cached.
+pub fn update(url: &str) -> Result<UpdateOutcome, String> {
+    let body = ureq::get(url)
+        .call()
+        .map_err(|e| format!("failed to fetch {}: {}", url, e))?
+        .body_mut()
+        .read_to_string()
+        .map_err(|e| format!("failed to read response body: {}", e))?;
+
+    let remote: RulesFile =
+        serde_json::from_str(&body).map_err(|e| format!("malformed rules.json from {}: {}", url, e))?;
+
+    let cached_version = load_cached_rules().map(|r| r.version);
+    let is_newer = match &cached_version {
+        Some(current) => compare_versions(&remote.version, current) == Some(std::cmp::Ordering::Greater),
+        None => true,
+    };
+
+    if !is_newer {
+        return Ok(UpdateOutcome::UpToDate { version: remote.version });
+    }
+
+    let dir = cache_dir();
+    fs::create_dir_all(&dir).map_err(|e| format!("failed to create cache dir {}: {}", dir.display(), e))?;
+    fs::write(cached_rules_path(), &body).map_err(|e| format!("failed to write cache: {}", e))?;
+
+    Ok(UpdateOutcome::Updated {
+        from: cached_version.unwrap_or_else(|| "none".to_string()),
+        to: remote.version,
+    })
+}